@@ -1,9 +1,44 @@
 extern crate floating_duration;
 
+use std::fmt;
 use std::time::{Duration, Instant};
 
 pub use floating_duration::TimeFormat;
 
+/// Exposes a `Duration` as a floating-point number of milliseconds or
+/// microseconds. (`Duration` already has a stable `as_secs_f64`.)
+///
+/// # Examples
+///
+/// ```
+/// extern crate elapsed;
+/// use elapsed::{measure_time, AsFloat};
+///
+/// fn main() {
+///     let (elapsed, _sum) = measure_time(|| {
+///         (0..10_000).sum::<u64>()
+///     });
+///     let millis: f64 = elapsed.0.as_millis_f64();
+///     println!("elapsed = {}ms", millis);
+/// }
+/// ```
+pub trait AsFloat {
+    /// Returns the duration as a floating-point number of milliseconds.
+    fn as_millis_f64(&self) -> f64;
+    /// Returns the duration as a floating-point number of microseconds.
+    fn as_micros_f64(&self) -> f64;
+}
+
+impl AsFloat for Duration {
+    fn as_millis_f64(&self) -> f64 {
+        self.as_secs_f64() * 1e3
+    }
+
+    fn as_micros_f64(&self) -> f64 {
+        self.as_secs_f64() * 1e6
+    }
+}
+
 /// Measures the time needed to execute a block of code.
 ///
 /// # Examples
@@ -29,3 +64,325 @@ pub fn measure_time<T, F: FnOnce() -> T>(f: F) -> (TimeFormat<Duration>, T) {
     let r = f();
     (TimeFormat(start.elapsed()), r)
 }
+
+/// Measures the time needed to execute a block of code, logging the start
+/// and end of the phase to stderr.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elapsed;
+/// use elapsed::report_time;
+///
+/// fn main() {
+///     let sum = report_time("summing", || {
+///         (0..10_000).sum::<u64>()
+///     });
+///     println!("sum = {}", sum);
+///
+///     // Prints to stderr
+///     // starting summing
+///     // done summing — took 227.812μs
+/// }
+/// ```
+pub fn report_time<T, F: FnOnce() -> T>(name: &str, f: F) -> T {
+    eprintln!("starting {}", name);
+    let (elapsed, r) = measure_time(f);
+    eprintln!("done {} — took {}", name, elapsed);
+    r
+}
+
+/// A stopwatch with lap support, for timing code that `measure_time`
+/// can't wrap in a single closure.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elapsed;
+/// use elapsed::Timer;
+///
+/// fn main() {
+///     let mut timer = Timer::start();
+///     for _ in 0..3 {
+///         // do some work
+///         println!("lap = {}", timer.lap());
+///     }
+///     println!("total = {}", timer.elapsed());
+/// }
+/// ```
+pub struct Timer {
+    origin: Instant,
+    lap: Instant,
+}
+
+impl Timer {
+    /// Starts the timer, recording the current instant as the origin.
+    pub fn start() -> Timer {
+        let now = Instant::now();
+        Timer {
+            origin: now,
+            lap: now,
+        }
+    }
+
+    /// Resets the origin (and the lap marker) to the current instant.
+    pub fn restart(&mut self) {
+        let now = Instant::now();
+        self.origin = now;
+        self.lap = now;
+    }
+
+    /// Returns the time elapsed since the timer was started or restarted.
+    pub fn elapsed(&self) -> TimeFormat<Duration> {
+        TimeFormat(self.origin.elapsed())
+    }
+
+    /// Returns the time elapsed since the last call to `lap` (or since
+    /// start, for the first call), and advances the lap marker.
+    pub fn lap(&mut self) -> TimeFormat<Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.lap);
+        self.lap = now;
+        TimeFormat(elapsed)
+    }
+}
+
+/// A `Duration` formatted as a list of descending human-readable
+/// components, e.g. `16d,8h,53m,36s` or `2h,27m,20s,3ms`.
+///
+/// Zero components are skipped wherever they occur. A duration with no
+/// non-zero component (including one shorter than a microsecond) is
+/// written as `0μs`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elapsed;
+/// use std::time::Duration;
+/// use elapsed::HumanTime;
+///
+/// fn main() {
+///     let d = Duration::new(2 * 3600 + 27 * 60 + 20, 3_000_000);
+///     assert_eq!(HumanTime(d).to_string(), "2h,27m,20s,3ms");
+/// }
+/// ```
+pub struct HumanTime(pub Duration);
+
+impl fmt::Display for HumanTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let total_secs = self.0.as_secs();
+        let days = total_secs / 86_400;
+        let hours = (total_secs % 86_400) / 3600;
+        let minutes = (total_secs % 3600) / 60;
+        let seconds = total_secs % 60;
+        let millis = self.0.subsec_millis();
+        let micros = self.0.subsec_micros() % 1000;
+
+        let components = [
+            (days, "d"),
+            (hours, "h"),
+            (minutes, "m"),
+            (seconds, "s"),
+            (millis as u64, "ms"),
+            (micros as u64, "μs"),
+        ];
+
+        let mut first = true;
+        for &(value, unit) in &components {
+            if value == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, ",")?;
+            }
+            write!(f, "{}{}", value, unit)?;
+            first = false;
+        }
+        if first {
+            write!(f, "0μs")?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a `Duration` using the multi-unit [`HumanTime`] representation.
+///
+/// [`HumanTime`]: struct.HumanTime.html
+pub fn format_human(duration: Duration) -> String {
+    HumanTime(duration).to_string()
+}
+
+/// Like [`measure_time`], but returns the elapsed time as a human-readable
+/// multi-unit string (see [`format_human`]) instead of a [`TimeFormat`].
+///
+/// [`measure_time`]: fn.measure_time.html
+/// [`format_human`]: fn.format_human.html
+/// [`TimeFormat`]: struct.TimeFormat.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate elapsed;
+/// use elapsed::measure_time_human;
+///
+/// fn main() {
+///     let (elapsed, sum) = measure_time_human(|| {
+///         (0..10_000).sum::<u64>()
+///     });
+///     println!("elapsed = {}", elapsed);
+///     println!("sum = {}", sum);
+/// }
+/// ```
+pub fn measure_time_human<T, F: FnOnce() -> T>(f: F) -> (String, T) {
+    let start = Instant::now();
+    let r = f();
+    (format_human(start.elapsed()), r)
+}
+
+/// Summary statistics over a set of measured `Duration`s, as produced by
+/// [`measure_times`].
+///
+/// [`measure_times`]: fn.measure_times.html
+pub struct Stats {
+    /// Every individual sample, in the order the measurements were taken.
+    pub samples: Vec<Duration>,
+    /// The shortest sample.
+    pub min: TimeFormat<Duration>,
+    /// The longest sample.
+    pub max: TimeFormat<Duration>,
+    /// The arithmetic mean of the samples.
+    pub mean: TimeFormat<Duration>,
+    /// The median of the samples.
+    pub median: TimeFormat<Duration>,
+    /// The standard deviation of the samples.
+    pub stddev: TimeFormat<Duration>,
+}
+
+impl Stats {
+    fn from_samples(samples: Vec<Duration>) -> Stats {
+        if samples.is_empty() {
+            return Stats {
+                samples,
+                min: TimeFormat(Duration::new(0, 0)),
+                max: TimeFormat(Duration::new(0, 0)),
+                mean: TimeFormat(Duration::new(0, 0)),
+                median: TimeFormat(Duration::new(0, 0)),
+                stddev: TimeFormat(Duration::new(0, 0)),
+            };
+        }
+
+        let mut sorted = samples.clone();
+        sorted.sort();
+        let min = *sorted.first().unwrap();
+        let max = *sorted.last().unwrap();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            nanos_to_duration((duration_to_nanos(sorted[n / 2 - 1]) + duration_to_nanos(sorted[n / 2])) / 2.0)
+        };
+
+        let mean_nanos = sorted.iter().map(|&d| duration_to_nanos(d)).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|&d| (duration_to_nanos(d) - mean_nanos).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            samples,
+            min: TimeFormat(min),
+            max: TimeFormat(max),
+            mean: TimeFormat(nanos_to_duration(mean_nanos)),
+            median: TimeFormat(median),
+            stddev: TimeFormat(nanos_to_duration(variance.sqrt())),
+        }
+    }
+}
+
+fn duration_to_nanos(d: Duration) -> f64 {
+    d.as_secs() as f64 * 1e9 + d.subsec_nanos() as f64
+}
+
+fn nanos_to_duration(nanos: f64) -> Duration {
+    let nanos = nanos.max(0.0);
+    Duration::new((nanos / 1e9) as u64, (nanos % 1e9) as u32)
+}
+
+/// Runs `f` `iters` times, recording the duration of each run, and returns
+/// summary [`Stats`] over the samples.
+///
+/// `iters == 0` returns a zeroed-out `Stats` with an empty `samples` list,
+/// rather than panicking.
+///
+/// [`Stats`]: struct.Stats.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate elapsed;
+/// use elapsed::measure_times;
+///
+/// fn main() {
+///     let stats = measure_times(1000, || {
+///         (0..10_000).sum::<u64>()
+///     });
+///     println!("mean = {}", stats.mean);
+///     println!("stddev = {}", stats.stddev);
+/// }
+/// ```
+pub fn measure_times<T, F: FnMut() -> T>(iters: usize, mut f: F) -> Stats {
+    let mut samples = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let start = Instant::now();
+        f();
+        samples.push(start.elapsed());
+    }
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_time_skips_zero_components() {
+        let d = Duration::new(16 * 86_400 + 8 * 3600 + 53 * 60 + 36, 0);
+        assert_eq!(HumanTime(d).to_string(), "16d,8h,53m,36s");
+    }
+
+    #[test]
+    fn human_time_zero_duration() {
+        assert_eq!(HumanTime(Duration::new(0, 0)).to_string(), "0μs");
+    }
+
+    #[test]
+    fn stats_even_sample_count_averages_the_middle_two() {
+        let samples = vec![
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+            Duration::from_millis(3),
+            Duration::from_millis(4),
+        ];
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.median.0, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn stats_single_sample() {
+        let stats = Stats::from_samples(vec![Duration::from_millis(5)]);
+        assert_eq!(stats.min.0, Duration::from_millis(5));
+        assert_eq!(stats.max.0, Duration::from_millis(5));
+        assert_eq!(stats.mean.0, Duration::from_millis(5));
+        assert_eq!(stats.median.0, Duration::from_millis(5));
+        assert_eq!(stats.stddev.0, Duration::new(0, 0));
+    }
+
+    #[test]
+    fn stats_zero_samples_is_zeroed_not_panicking() {
+        let stats = Stats::from_samples(vec![]);
+        assert!(stats.samples.is_empty());
+        assert_eq!(stats.mean.0, Duration::new(0, 0));
+    }
+}